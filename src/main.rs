@@ -1,4 +1,4 @@
-use std::{env::args, fs::File, io::stdout, io::Write, time::Instant};
+use std::{env::args, fmt, fs::File, io::stdout, io::Write, process::exit, time::Instant};
 
 use ahash::AHashMap;
 use memmap2::Mmap;
@@ -11,14 +11,55 @@ const THREADS: usize = 10;
 fn main() {
     let start = Instant::now();
 
-    let path = args().nth(1).expect("missing input file");
+    // `--generic` trades the fast i16-tenths path for one that handles arbitrary decimal
+    // precision. `--strict` trades the unchecked fast path for one that never panics or
+    // reads out of bounds on malformed input; `--skip-malformed` makes it skip bad lines
+    // instead of aborting. Everything else is the input path.
+    let mut generic = false;
+    let mut strict = false;
+    let mut skip_malformed = false;
+    let mut path = None;
+    for arg in args().skip(1) {
+        match arg.as_str() {
+            "--generic" => generic = true,
+            "--strict" => strict = true,
+            "--skip-malformed" => skip_malformed = true,
+            _ => path = Some(arg),
+        }
+    }
+    let path = path.expect("missing input file");
+
     let fp = File::open(path).expect("failed to open input file");
     let input = unsafe { Mmap::map(&fp).expect("failed to map file") };
 
+    if generic {
+        run_generic(&input);
+    } else if strict {
+        run_strict(&input, skip_malformed);
+    } else {
+        run_fast(&input);
+    }
+
+    let elapsed = start.elapsed();
+    eprintln!("Elapsed: {} ms", elapsed.as_millis());
+}
+
+/// Runs the fast path: fixed one-fractional-digit parsing into i16/i64 tenths of a degree.
+/// This is the default, and what the benchmarks target.
+fn run_fast(input: &[u8]) {
     let chunk_size = input.len() / THREADS;
     let cities = (0..THREADS)
         .into_par_iter()
-        .map(|thread| process_chunk(&input, thread * chunk_size, (1 + thread) * chunk_size))
+        .map(|thread| {
+            // The last chunk takes the remainder too, so a length that isn't an exact
+            // multiple of THREADS (or is smaller than THREADS) doesn't drop trailing bytes.
+            let to = if thread == THREADS - 1 {
+                input.len()
+            } else {
+                (1 + thread) * chunk_size
+            };
+            process_chunk(input, thread * chunk_size, to)
+        })
         .reduce_with(merge_results)
         .unwrap();
 
@@ -43,17 +84,111 @@ fn main() {
         }
     }
     writeln!(lock, "}}").unwrap();
+}
 
-    let elapsed = start.elapsed();
-    eprintln!("Elapsed: {} ms", elapsed.as_millis());
+/// Runs the `--generic` path: variable-precision decimal parsing into i128 fixed-point,
+/// normalized to `SCALE` fractional digits. Slower than `run_fast`, but not limited to the
+/// official 1BRC format.
+fn run_generic(input: &[u8]) {
+    let chunk_size = input.len() / THREADS;
+    let cities = (0..THREADS)
+        .into_par_iter()
+        .map(|thread| {
+            // The last chunk takes the remainder too, so a length that isn't an exact
+            // multiple of THREADS (or is smaller than THREADS) doesn't drop trailing bytes.
+            let to = if thread == THREADS - 1 {
+                input.len()
+            } else {
+                (1 + thread) * chunk_size
+            };
+            process_chunk_generic(input, thread * chunk_size, to)
+        })
+        .reduce_with(merge_results_generic)
+        .unwrap();
+
+    // The challenge states that there are at most 10_000 cities, so we can pre-allocate.
+    let mut result = Vec::with_capacity(10_000);
+    result.extend(cities);
+    let result_count = result.len();
+    result.sort_unstable_by_key(|x| x.0);
+
+    let mut lock = stdout().lock();
+    write!(lock, "{{").unwrap();
+    for (idx, (city, entry)) in result.into_iter().enumerate() {
+        let mean = (entry.sum as f64 / entry.count as f64).round() as i64;
+        write!(lock, "{}=", unsafe { std::str::from_utf8_unchecked(city) }).unwrap();
+        write_fixed(&mut lock, entry.min);
+        write!(lock, "/").unwrap();
+        write_fixed(&mut lock, mean);
+        write!(lock, "/").unwrap();
+        write_fixed(&mut lock, entry.max);
+        if idx != result_count - 1 {
+            write!(lock, ",").unwrap();
+        }
+    }
+    writeln!(lock, "}}").unwrap();
+}
+
+/// Runs the `--strict` path: the same i16-tenths aggregation as `run_fast`, but parsed
+/// with bounds-checked, fully safe code that reports malformed input via `ParseError`
+/// instead of relying on the challenge's well-formedness guarantees. With
+/// `skip_malformed` set, malformed lines are skipped instead of aborting the run.
+fn run_strict(input: &[u8], skip_malformed: bool) {
+    let chunk_size = input.len() / THREADS;
+    let chunks: Result<Vec<_>, ParseError> = (0..THREADS)
+        .into_par_iter()
+        .map(|thread| {
+            // The last chunk takes the remainder too, so a length that isn't an exact
+            // multiple of THREADS (or is smaller than THREADS) doesn't drop trailing bytes.
+            let to = if thread == THREADS - 1 {
+                input.len()
+            } else {
+                (1 + thread) * chunk_size
+            };
+            process_chunk_strict(input, thread * chunk_size, to, skip_malformed)
+        })
+        .collect();
+
+    let cities = match chunks {
+        Ok(chunks) => chunks.into_iter().reduce(merge_results).unwrap(),
+        Err(err) => {
+            eprintln!("error: {err}");
+            exit(1);
+        }
+    };
+
+    // The challenge states that there are at most 10_000 cities, so we can pre-allocate.
+    let mut result = Vec::with_capacity(10_000);
+    result.extend(cities);
+    let result_count = result.len();
+    result.sort_unstable_by_key(|x| x.0);
+
+    let mut lock = stdout().lock();
+    write!(lock, "{{").unwrap();
+    for (idx, (city, entry)) in result.into_iter().enumerate() {
+        let mean = (entry.sum as f64 / entry.count as f64).round() as i16;
+        write!(lock, "{}=", unsafe { std::str::from_utf8_unchecked(city) }).unwrap();
+        write_i16_as_float(&mut lock, entry.min);
+        write!(lock, "/").unwrap();
+        write_i16_as_float(&mut lock, mean);
+        write!(lock, "/").unwrap();
+        write_i16_as_float(&mut lock, entry.max);
+        if idx != result_count - 1 {
+            write!(lock, ",").unwrap();
+        }
+    }
+    writeln!(lock, "}}").unwrap();
 }
 
 fn process_chunk(input: &[u8], from: usize, to: usize) -> AHashMap<&[u8], Entry> {
     let mut head = from;
 
-    // If starting in the middle, skip the first complete line, move head to the first character of
-    // the next line. The previous chunk will include the line that straddles the boundary.
-    if head != 0 {
+    // If starting in the middle of a line, skip the rest of it, moving head to the first
+    // character of the next line; the previous chunk will include the line that straddles
+    // the boundary. If `from` happens to land exactly on a line start (the preceding byte
+    // is `\n`), there's no straddling line to skip, and the previous chunk's `head < to`
+    // check already excluded this one, so it must be processed here.
+    if head != 0 && unsafe { *input.get_unchecked(head - 1) } != b'\n' {
         while unsafe { *input.get_unchecked(head) } != b'\n' {
             head += 1;
         }
@@ -66,20 +201,15 @@ fn process_chunk(input: &[u8], from: usize, to: usize) -> AHashMap<&[u8], Entry>
 
     while head < to {
         // We know the first byte on the line has to be a name, so we don't need to look at it yet.
-        let mut tail = head + 1;
-
         // We first search for the semicolon, which is the end of the city name.
-        while unsafe { input.get_unchecked(tail) } != &b';' {
-            tail += 1;
-        }
-        let semicolon = tail;
+        let semicolon = find_semicolon(input, head + 1);
 
         // Get the city name.
         let city = unsafe { input.get_unchecked(head..semicolon) };
 
         // After the semicolon, there are 3-5 bytes of temperature reading, depending on the sign
         // and the number of digits. Step onto the first of those bytes.
-        tail += 1;
+        let mut tail = semicolon + 1;
 
         // Parse the temperature reading into tenths of degrees.
         let reading = parse_i16(input, &mut tail);
@@ -94,6 +224,38 @@ fn process_chunk(input: &[u8], from: usize, to: usize) -> AHashMap<&[u8], Entry>
     cities
 }
 
+/// Finds the offset of the next `;` at or after `from`, searching 8 bytes at a time.
+/// Falls back to a scalar scan for the final partial word so the word load never reads
+/// past the end of `input`.
+#[inline]
+fn find_semicolon(input: &[u8], from: usize) -> usize {
+    let mut tail = from;
+
+    while tail + 8 <= input.len() {
+        let word = u64::from_le_bytes(unsafe {
+            input
+                .get_unchecked(tail..tail + 8)
+                .try_into()
+                .unwrap_unchecked()
+        });
+
+        // Classic SWAR zero-byte trick: XOR against a broadcast ';' turns matching bytes
+        // into zero, then this detects which (if any) byte of the word is zero.
+        let x = word ^ 0x3B3B3B3B3B3B3B3B;
+        let hit = x.wrapping_sub(0x0101010101010101) & !x & 0x8080808080808080;
+        if hit != 0 {
+            return tail + (hit.trailing_zeros() >> 3) as usize;
+        }
+        tail += 8;
+    }
+
+    // Fewer than 8 bytes remain before EOF; fall back to the scalar scan.
+    while unsafe { input.get_unchecked(tail) } != &b';' {
+        tail += 1;
+    }
+    tail
+}
+
 #[inline]
 fn merge_results<'a>(
     mut a: AHashMap<&'a [u8], Entry>,
@@ -148,6 +310,7 @@ fn insert_reading<'a>(cities: &mut AHashMap<&'a [u8], Entry>, city: &'a [u8], re
         });
 }
 
+#[derive(Debug)]
 struct Entry {
     /// Minimum reading, in tenths of a degree (x10).
     min: i16,
@@ -173,12 +336,433 @@ fn write_i16_as_float(mut destination: impl Write, value: i16) {
     .unwrap();
 }
 
+/// Number of fractional digits `--generic` mode normalizes every reading to.
+const SCALE: u32 = 4;
+
+/// `process_chunk` for `--generic` mode: same line-splitting logic, but parses readings
+/// with `parse_fixed` instead of the i16-tenths-only `parse_i16`.
+fn process_chunk_generic(input: &[u8], from: usize, to: usize) -> AHashMap<&[u8], GenericEntry> {
+    let mut head = from;
+
+    // If starting in the middle of a line, skip the rest of it, moving head to the first
+    // character of the next line; the previous chunk will include the line that straddles
+    // the boundary. If `from` happens to land exactly on a line start (the preceding byte
+    // is `\n`), there's no straddling line to skip, and the previous chunk's `head < to`
+    // check already excluded this one, so it must be processed here.
+    if head != 0 && unsafe { *input.get_unchecked(head - 1) } != b'\n' {
+        while unsafe { *input.get_unchecked(head) } != b'\n' {
+            head += 1;
+        }
+        head += 1
+    };
+
+    // The challenge states that there are at most 10_000 cities, so we can pre-allocate.
+    let mut cities: AHashMap<&[u8], GenericEntry> = AHashMap::default();
+    cities.reserve(10_000);
+
+    while head < to {
+        // We know the first byte on the line has to be a name, so we don't need to look at it yet.
+        // We first search for the semicolon, which is the end of the city name.
+        let semicolon = find_semicolon(input, head + 1);
+
+        // Get the city name.
+        let city = unsafe { input.get_unchecked(head..semicolon) };
+
+        // After the semicolon, there are the temperature reading's bytes. Step onto the first
+        // of those bytes.
+        let mut tail = semicolon + 1;
+
+        // Parse the temperature reading, normalized to `SCALE` fractional digits.
+        let reading = parse_fixed(input, &mut tail);
+
+        // Add the new reading.
+        insert_reading_generic(&mut cities, city, reading);
+
+        // Move head onto the first character of the next line.
+        head = tail + 1;
+    }
+
+    cities
+}
+
+#[inline]
+fn merge_results_generic<'a>(
+    mut a: AHashMap<&'a [u8], GenericEntry>,
+    b: AHashMap<&'a [u8], GenericEntry>,
+) -> AHashMap<&'a [u8], GenericEntry> {
+    b.into_iter().for_each(|(city, entry)| {
+        upsert_entry_generic(&mut a, city, entry);
+    });
+    a
+}
+
+#[inline]
+fn upsert_entry_generic<'a>(
+    cities: &mut AHashMap<&'a [u8], GenericEntry>,
+    city: &'a [u8],
+    entry: GenericEntry,
+) {
+    if let Some(GenericEntry {
+        ref mut min,
+        ref mut max,
+        ref mut sum,
+        ref mut count,
+    }) = cities.get_mut(city)
+    {
+        *min = (*min).min(entry.min);
+        *max = (*max).max(entry.max);
+        *sum += entry.sum;
+        *count += entry.count;
+    } else {
+        cities.insert(city, entry);
+    }
+}
+
+#[inline]
+fn insert_reading_generic<'a>(
+    cities: &mut AHashMap<&'a [u8], GenericEntry>,
+    city: &'a [u8],
+    reading: i64,
+) {
+    cities
+        .entry(city)
+        .and_modify(
+            |GenericEntry {
+                 min,
+                 max,
+                 sum,
+                 count,
+             }| {
+                *min = (*min).min(reading);
+                *max = (*max).max(reading);
+                *sum += reading as i128;
+                *count += 1;
+            },
+        )
+        .or_insert_with(|| GenericEntry {
+            min: reading,
+            max: reading,
+            sum: reading as i128,
+            count: 1,
+        });
+}
+
+struct GenericEntry {
+    /// Minimum reading, scaled by 10^SCALE.
+    min: i64,
+    /// Maximum reading, scaled by 10^SCALE.
+    max: i64,
+    /// Sum of all readings, scaled by 10^SCALE.
+    sum: i128,
+    /// Number of readings.
+    count: u32,
+}
+
+/// A line in `--strict` mode that didn't match the expected `name;[-]d[d].d` shape.
+#[derive(Debug, PartialEq, Eq)]
+struct ParseError {
+    /// Byte offset into the input where the malformed line starts.
+    offset: usize,
+    kind: ParseErrorKind,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum ParseErrorKind {
+    /// The line has no `;` separating the city name from the temperature reading.
+    MissingSemicolon,
+    /// The temperature field was empty, or was just a sign with no digits.
+    EmptyTemperatureField,
+    /// A byte in the temperature field, other than a leading `-` and the decimal point,
+    /// wasn't an ASCII digit.
+    InvalidTemperatureByte(u8),
+    /// The temperature field didn't have exactly one digit after the decimal point, had
+    /// no digit before it, or had no decimal point at all.
+    WrongFractionalDigitCount,
+    /// The temperature field's digits don't fit in an `i16` of tenths of a degree.
+    ReadingOutOfRange,
+    /// The input ended before the line was terminated by `\n`.
+    TruncatedLine,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            ParseErrorKind::MissingSemicolon => {
+                write!(f, "line at offset {} has no ';'", self.offset)
+            }
+            ParseErrorKind::EmptyTemperatureField => {
+                write!(f, "line at offset {} has an empty temperature field", self.offset)
+            }
+            ParseErrorKind::InvalidTemperatureByte(byte) => write!(
+                f,
+                "line at offset {} has an invalid temperature byte {byte:#04x}",
+                self.offset
+            ),
+            ParseErrorKind::WrongFractionalDigitCount => write!(
+                f,
+                "line at offset {} doesn't have digits before the decimal point and exactly one after it",
+                self.offset
+            ),
+            ParseErrorKind::ReadingOutOfRange => {
+                write!(f, "line at offset {} has a reading that overflows i16", self.offset)
+            }
+            ParseErrorKind::TruncatedLine => write!(
+                f,
+                "line at offset {} is truncated: missing trailing newline",
+                self.offset
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// `process_chunk` for `--strict` mode: the same line-splitting logic, but using
+/// bounds-checked slice indexing throughout instead of `get_unchecked`, so a malformed
+/// or truncated chunk reports a `ParseError` instead of causing UB or an out-of-bounds
+/// read. When `skip_malformed` is set, a malformed line is skipped instead of aborting
+/// the whole chunk.
+fn process_chunk_strict(
+    input: &[u8],
+    from: usize,
+    to: usize,
+    skip_malformed: bool,
+) -> Result<AHashMap<&[u8], Entry>, ParseError> {
+    let mut head = from;
+
+    // If starting in the middle of a line, skip the rest of it, moving head to the first
+    // character of the next line; the previous chunk will include the line that straddles
+    // the boundary. If `from` happens to land exactly on a line start (the preceding byte
+    // is `\n`), there's no straddling line to skip, and the previous chunk's `head < to`
+    // check already excluded this one, so it must be processed here.
+    if head != 0 && input[head - 1] != b'\n' {
+        match input[head..].iter().position(|&b| b == b'\n') {
+            Some(pos) => head += pos + 1,
+            None => return Ok(AHashMap::default()),
+        }
+    }
+
+    // The challenge states that there are at most 10_000 cities, so we can pre-allocate.
+    let mut cities: AHashMap<&[u8], Entry> = AHashMap::default();
+    cities.reserve(10_000);
+
+    while head < to && head < input.len() {
+        match parse_line_strict(input, head) {
+            Ok((city, reading, next)) => {
+                insert_reading(&mut cities, city, reading);
+                head = next;
+            }
+            Err(err) => {
+                if !skip_malformed {
+                    return Err(err);
+                }
+                // Skip to the next line, or give up if the input ends first.
+                match input[head..].iter().position(|&b| b == b'\n') {
+                    Some(pos) => head += pos + 1,
+                    None => break,
+                }
+            }
+        }
+    }
+
+    Ok(cities)
+}
+
+/// Parses one `name;[-]d[d].d\n` line starting at `head`, fully bounds-checked.
+/// Returns the city name, the parsed reading, and the offset of the next line.
+fn parse_line_strict(input: &[u8], head: usize) -> Result<(&[u8], i16, usize), ParseError> {
+    // Find the end of the line first, so a missing ';' is never mistaken for one that
+    // belongs to a later line.
+    let newline = match input[head..].iter().position(|&b| b == b'\n') {
+        Some(pos) => head + pos,
+        None => {
+            return Err(ParseError {
+                offset: head,
+                kind: ParseErrorKind::TruncatedLine,
+            })
+        }
+    };
+    let line = &input[head..newline];
+
+    let semicolon = match line.iter().position(|&b| b == b';') {
+        Some(pos) => head + pos,
+        None => {
+            return Err(ParseError {
+                offset: head,
+                kind: ParseErrorKind::MissingSemicolon,
+            })
+        }
+    };
+    let city = &input[head..semicolon];
+    let reading = parse_i16_checked(&input[semicolon + 1..newline], head)?;
+
+    Ok((city, reading, newline + 1))
+}
+
+/// Parses a temperature field with bounds-checked, branch-heavy but fully safe code.
+/// `line_offset` is the start of the line, used to locate the field in a `ParseError`.
+fn parse_i16_checked(field: &[u8], line_offset: usize) -> Result<i16, ParseError> {
+    let err = |kind| ParseError {
+        offset: line_offset,
+        kind,
+    };
+
+    let (negative, digits) = match field.first() {
+        Some(b'-') => (true, &field[1..]),
+        Some(_) => (false, field),
+        None => return Err(err(ParseErrorKind::EmptyTemperatureField)),
+    };
+
+    if digits.is_empty() {
+        return Err(err(ParseErrorKind::EmptyTemperatureField));
+    }
+
+    // We record tenths of a degree, so there must be exactly one digit after the `.`,
+    // and at least one digit before it.
+    let (int_part, frac_part) = match digits.iter().position(|&b| b == b'.') {
+        Some(pos) => (&digits[..pos], &digits[pos + 1..]),
+        None => return Err(err(ParseErrorKind::WrongFractionalDigitCount)),
+    };
+    if int_part.is_empty() || frac_part.len() != 1 {
+        return Err(err(ParseErrorKind::WrongFractionalDigitCount));
+    }
+
+    let mut reading = 0_i16;
+    for &byte in int_part.iter().chain(frac_part) {
+        match byte {
+            b'0'..=b'9' => {
+                reading = reading
+                    .checked_mul(10)
+                    .and_then(|r| r.checked_add((byte - b'0') as i16))
+                    .ok_or_else(|| err(ParseErrorKind::ReadingOutOfRange))?
+            }
+            other => return Err(err(ParseErrorKind::InvalidTemperatureByte(other))),
+        }
+    }
+
+    Ok(if negative { -reading } else { reading })
+}
+
+/// Writes a `SCALE`-scaled fixed-point value as a float with `SCALE` fractional digits.
+#[inline]
+fn write_fixed(mut destination: impl Write, value: i64) {
+    let divisor = 10_i64.pow(SCALE);
+    let abs_value = value.abs();
+    write!(
+        destination,
+        "{sign}{int}.{frac:0width$}",
+        sign = if value < 0 { "-" } else { "" },
+        int = abs_value / divisor,
+        frac = abs_value % divisor,
+        width = SCALE as usize,
+    )
+    .unwrap();
+}
+
+/// Parses a byte slice as a fixed-point value, assuming it's non-empty and valid.
+/// Unlike `parse_i16`, supports a variable number of fractional digits, normalizing the
+/// result to `SCALE` fractional digits so readings of differing precision can be summed.
+/// Uses the passed ptr reference into the input as the read head, and stops at the first
+/// byte that isn't a digit or the (first) decimal point, leaving `*ptr` on that byte.
+fn parse_fixed(input: &[u8], ptr: &mut usize) -> i64 {
+    let negative = unsafe { *input.get_unchecked(*ptr) } == b'-';
+    if negative {
+        *ptr += 1;
+    }
+
+    let mut value = 0_i64;
+    let mut scale = 0_u32;
+    let mut seen_dot = false;
+    loop {
+        let byte = unsafe { *input.get_unchecked(*ptr) };
+        match byte {
+            b'0'..=b'9' => {
+                value = value * 10 + (byte - b'0') as i64;
+                if seen_dot {
+                    scale += 1;
+                }
+                *ptr += 1;
+            }
+            b'.' if !seen_dot => {
+                seen_dot = true;
+                *ptr += 1;
+            }
+            _ => break,
+        }
+    }
+
+    // Normalize to SCALE fractional digits so values of differing precision can be
+    // folded into the same sum. Scale up if there were fewer than SCALE fractional
+    // digits, or truncate the excess precision if there were more.
+    if scale > SCALE {
+        value /= 10_i64.pow(scale - SCALE);
+    } else {
+        value *= 10_i64.pow(SCALE - scale);
+    }
+
+    if negative {
+        value *= -1;
+    }
+
+    value
+}
+
 /// Parses a byte slice as i16, assuming it's non-empty and valid.
 /// Skips over the decimal point and records exactly one fractional digit.
 /// Uses the passed ptr reference into the input as the read head.
-/// We really, really need this to be inlined, and rustc makes us ask for it.
+///
+/// Dispatches to the branchless SWAR parser when a full 8-byte word can be read
+/// without crossing the end of `input`, and falls back to the scalar parser for
+/// the final few bytes of the mmap where that would read out of bounds.
 #[inline(always)]
 fn parse_i16(input: &[u8], ptr: &mut usize) -> i16 {
+    if input.len() - *ptr >= 8 {
+        parse_i16_swar(input, ptr)
+    } else {
+        parse_i16_scalar(input, ptr)
+    }
+}
+
+/// Parses a `[-]d[d].d` temperature field out of a single 8-byte word, branchlessly.
+/// Requires at least 8 bytes to be readable starting at `*ptr`.
+/// We really, really need this to be inlined, and rustc makes us ask for it.
+#[inline(always)]
+fn parse_i16_swar(input: &[u8], ptr: &mut usize) -> i16 {
+    let word = u64::from_le_bytes(unsafe {
+        input
+            .get_unchecked(*ptr..*ptr + 8)
+            .try_into()
+            .unwrap_unchecked()
+    });
+
+    // ASCII digits (0x30..=0x39) have bit 4 set, while '.' (0x2E) does not, so this
+    // locates the decimal point's byte position within the word.
+    let dot = (!word & 0x10101000).trailing_zeros();
+
+    // All bits set if the first byte is '-' (0x2D), zero otherwise.
+    let signed = ((!word << 59) as i64) >> 63;
+
+    // Shift the digits so the hundreds/tens/units nibbles land at fixed byte offsets,
+    // masking out the sign byte first if there is one.
+    let shift = 28 - dot;
+    let digits = ((word & !(signed as u64 & 0xFF)) << shift) & 0x0F000F0F00;
+
+    // The magic multiplier folds the digits into tenths of a degree, weighted 100/10/1.
+    let abs = ((digits.wrapping_mul(0x640a0001)) >> 32) & 0x3FF;
+    let reading = ((abs as i64 ^ signed) - signed) as i16;
+
+    // Land on the newline, same as the scalar parser, so the caller's `+ 1` finds the
+    // start of the next line.
+    *ptr += (dot as usize >> 3) + 2;
+
+    reading
+}
+
+/// Parses a byte slice as i16, assuming it's non-empty and valid.
+/// Skips over the decimal point and records exactly one fractional digit.
+/// Uses the passed ptr reference into the input as the read head.
+/// Scalar fallback for `parse_i16_swar`, used where fewer than 8 bytes remain in `input`.
+fn parse_i16_scalar(input: &[u8], ptr: &mut usize) -> i16 {
     // Check if the first byte is a minus. If so, record that fact and step ahead.
     let negative = unsafe { *input.get_unchecked(*ptr) } == b'-';
     if negative {
@@ -276,6 +860,50 @@ mod tests {
         assert_eq!(entry.count, 2);
     }
 
+    #[test]
+    fn test_process_chunk_boundary_at_line_start() {
+        // `from` lands exactly on the start of "City2", i.e. right after the previous
+        // line's '\n'. There's no straddling line to skip, so this chunk must still
+        // pick up "City2" rather than dropping it.
+        let input = b"City1;-12.3\nCity2;12.3\n";
+        let cities = process_chunk(input, 12, input.len());
+        assert_eq!(cities.len(), 1);
+        let entry = cities.get(&input[12..17]).unwrap();
+        assert_eq!(entry.min, 123);
+        assert_eq!(entry.count, 1);
+    }
+
+    #[test]
+    fn test_find_semicolon_short_name() {
+        let input = b"Ab;1.0\n";
+        assert_eq!(2, find_semicolon(input, 0));
+    }
+
+    #[test]
+    fn test_find_semicolon_name_not_multiple_of_eight() {
+        // "Reykjavik" is 9 bytes, so the semicolon falls in the second 8-byte word.
+        let input = b"Reykjavik;12.3\n";
+        assert_eq!(9, find_semicolon(input, 0));
+    }
+
+    #[test]
+    fn test_find_semicolon_name_with_multibyte_utf8() {
+        // "São Paulo" contains the 2-byte UTF-8 sequence 0xC3 0xA3 ('ã'), whose high bytes
+        // must not be mistaken for a ';' (0x3B) by the SWAR mask.
+        let input = "São Paulo;12.3\n".as_bytes();
+        assert_eq!(10, find_semicolon(input, 0));
+        assert_eq!(b';', input[10]);
+    }
+
+    #[test]
+    fn test_process_chunk_name_longer_than_one_word() {
+        let input = b"Port-au-Prince;-1.2\n";
+        let cities = process_chunk(input, 0, input.len());
+        assert_eq!(cities.len(), 1);
+        let entry = cities.get(&input[0..14]).unwrap();
+        assert_eq!(entry.min, -12);
+    }
+
     #[test]
     fn test_parse_i16() {
         assert_eq!(123, parse_i16(b"12.3", &mut 0));
@@ -288,4 +916,237 @@ mod tests {
         parse_i16(b"1.1\nfoo", &mut ptr);
         assert_eq!(3, ptr);
     }
+
+    #[test]
+    fn test_parse_i16_swar() {
+        assert_eq!(123, parse_i16_swar(b"12.3\nfoo", &mut 0));
+        assert_eq!(-123, parse_i16_swar(b"-12.3\nfo", &mut 0));
+        assert_eq!(12, parse_i16_swar(b"1.2\nfoofoo", &mut 0));
+        assert_eq!(-12, parse_i16_swar(b"-1.2\nfoofoo", &mut 0));
+    }
+
+    #[test]
+    fn test_parse_i16_swar_updates_ptr() {
+        let mut ptr = 0;
+        parse_i16_swar(b"1.1\nfoofoo", &mut ptr);
+        assert_eq!(3, ptr);
+    }
+
+    #[test]
+    fn test_write_fixed() {
+        let mut buf = Vec::new();
+        write_fixed(&mut buf, 1230000);
+        assert_eq!(buf, b"123.0000");
+    }
+
+    #[test]
+    fn test_write_negative_fixed() {
+        let mut buf = Vec::new();
+        write_fixed(&mut buf, -1230000);
+        assert_eq!(buf, b"-123.0000");
+    }
+
+    #[test]
+    fn test_parse_fixed_one_fractional_digit() {
+        assert_eq!(1230000, parse_fixed(b"123.0\n", &mut 0));
+        assert_eq!(-1230000, parse_fixed(b"-123.0\n", &mut 0));
+    }
+
+    #[test]
+    fn test_parse_fixed_several_fractional_digits() {
+        assert_eq!(1234500, parse_fixed(b"123.45\n", &mut 0));
+        assert_eq!(1234567, parse_fixed(b"123.4567\n", &mut 0));
+    }
+
+    #[test]
+    fn test_parse_fixed_more_than_scale_fractional_digits() {
+        // SCALE is 4, so the 5th fractional digit is truncated rather than silently
+        // inflating the value's scale.
+        assert_eq!(12345, parse_fixed(b"1.23456\n", &mut 0));
+        assert_eq!(-12345, parse_fixed(b"-1.23456\n", &mut 0));
+    }
+
+    #[test]
+    fn test_parse_fixed_no_fractional_digits() {
+        assert_eq!(1230000, parse_fixed(b"123\n", &mut 0));
+    }
+
+    #[test]
+    fn test_process_chunk_generic_one_line() {
+        let input = b"City;-12.345\n";
+        let cities = process_chunk_generic(input, 0, input.len());
+        assert_eq!(cities.len(), 1);
+        let entry = cities.get(&input[0..4]).unwrap();
+        assert_eq!(entry.min, -123450);
+        assert_eq!(entry.max, -123450);
+        assert_eq!(entry.sum, -123450);
+        assert_eq!(entry.count, 1);
+    }
+
+    #[test]
+    fn test_process_chunk_generic_mixed_precision_same_city() {
+        let input = b"City;-1.2\nCity;0.001\n";
+        let cities = process_chunk_generic(input, 0, input.len());
+        assert_eq!(cities.len(), 1);
+        let entry = cities.get(&input[0..4]).unwrap();
+        assert_eq!(entry.min, -12000);
+        assert_eq!(entry.max, 10);
+        assert_eq!(entry.sum, -11990);
+        assert_eq!(entry.count, 2);
+    }
+
+    #[test]
+    fn test_process_chunk_generic_boundary_at_line_start() {
+        // Same as `test_process_chunk_boundary_at_line_start`, but for the `--generic`
+        // path: `from` lands exactly on the start of "City2", so it must be processed
+        // here rather than silently dropped.
+        let input = b"City1;-12.3\nCity2;12.3\n";
+        let cities = process_chunk_generic(input, 12, input.len());
+        assert_eq!(cities.len(), 1);
+        let entry = cities.get(&input[12..17]).unwrap();
+        assert_eq!(entry.min, 123000);
+        assert_eq!(entry.count, 1);
+    }
+
+    #[test]
+    fn test_process_chunk_strict_valid_input() {
+        let input = b"City1;-12.3\nCity2;12.3\n";
+        let cities = process_chunk_strict(input, 0, input.len(), false).unwrap();
+        assert_eq!(cities.len(), 2);
+        let entry = cities.get(&input[0..5]).unwrap();
+        assert_eq!(entry.min, -123);
+        let entry = cities.get(&input[12..17]).unwrap();
+        assert_eq!(entry.min, 123);
+    }
+
+    #[test]
+    fn test_process_chunk_strict_boundary_at_line_start() {
+        // Same as `test_process_chunk_boundary_at_line_start`, but for the `--strict`
+        // path: `from` lands exactly on the start of "City2", so it must be processed
+        // here rather than silently dropped.
+        let input = b"City1;-12.3\nCity2;12.3\n";
+        let cities = process_chunk_strict(input, 12, input.len(), false).unwrap();
+        assert_eq!(cities.len(), 1);
+        let entry = cities.get(&input[12..17]).unwrap();
+        assert_eq!(entry.min, 123);
+        assert_eq!(entry.count, 1);
+    }
+
+    #[test]
+    fn test_process_chunk_strict_missing_semicolon() {
+        let input = b"City-12.3\n";
+        let err = process_chunk_strict(input, 0, input.len(), false).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                offset: 0,
+                kind: ParseErrorKind::MissingSemicolon
+            }
+        );
+    }
+
+    #[test]
+    fn test_process_chunk_strict_missing_trailing_newline() {
+        let input = b"City;12.3";
+        let err = process_chunk_strict(input, 0, input.len(), false).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                offset: 0,
+                kind: ParseErrorKind::TruncatedLine
+            }
+        );
+    }
+
+    #[test]
+    fn test_process_chunk_strict_empty_temperature_field() {
+        let input = b"City;\n";
+        let err = process_chunk_strict(input, 0, input.len(), false).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                offset: 0,
+                kind: ParseErrorKind::EmptyTemperatureField
+            }
+        );
+    }
+
+    #[test]
+    fn test_process_chunk_strict_garbage_temperature_byte() {
+        let input = b"City;1x.3\n";
+        let err = process_chunk_strict(input, 0, input.len(), false).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                offset: 0,
+                kind: ParseErrorKind::InvalidTemperatureByte(b'x')
+            }
+        );
+    }
+
+    #[test]
+    fn test_process_chunk_strict_reading_out_of_range_does_not_panic() {
+        // A garbage field long enough to overflow i16 must report an error, not panic.
+        let input = b"City;99999.9\n";
+        let err = process_chunk_strict(input, 0, input.len(), false).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                offset: 0,
+                kind: ParseErrorKind::ReadingOutOfRange
+            }
+        );
+    }
+
+    #[test]
+    fn test_process_chunk_strict_skip_malformed() {
+        let input = b"City;12.3\nGarbage-Line\nCity;-1.0\n";
+        let cities = process_chunk_strict(input, 0, input.len(), true).unwrap();
+        let entry = cities.get(&input[0..4]).unwrap();
+        assert_eq!(entry.min, -10);
+        assert_eq!(entry.max, 123);
+        assert_eq!(entry.count, 2);
+    }
+
+    #[test]
+    fn test_parse_i16_checked_valid() {
+        assert_eq!(123, parse_i16_checked(b"12.3", 0).unwrap());
+        assert_eq!(-123, parse_i16_checked(b"-12.3", 0).unwrap());
+    }
+
+    #[test]
+    fn test_parse_i16_checked_empty() {
+        assert_eq!(
+            ParseErrorKind::EmptyTemperatureField,
+            parse_i16_checked(b"", 0).unwrap_err().kind
+        );
+        assert_eq!(
+            ParseErrorKind::EmptyTemperatureField,
+            parse_i16_checked(b"-", 0).unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn test_parse_i16_checked_wrong_fractional_digit_count() {
+        assert_eq!(
+            ParseErrorKind::WrongFractionalDigitCount,
+            parse_i16_checked(b"12.34", 0).unwrap_err().kind
+        );
+        assert_eq!(
+            ParseErrorKind::WrongFractionalDigitCount,
+            parse_i16_checked(b"12.", 0).unwrap_err().kind
+        );
+        assert_eq!(
+            ParseErrorKind::WrongFractionalDigitCount,
+            parse_i16_checked(b"12", 0).unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn test_parse_i16_checked_reading_out_of_range() {
+        assert_eq!(
+            ParseErrorKind::ReadingOutOfRange,
+            parse_i16_checked(b"99999.9", 0).unwrap_err().kind
+        );
+    }
 }